@@ -0,0 +1,309 @@
+//! Contains raw esc/pos commands
+//!
+//! Most of the time you will not need to interact with this module directly, as
+//! the [Instruction](crate::Instruction) and [Printer](crate::Printer) structures
+//! already emit the relevant byte sequences for you. It is exposed mainly so that
+//! callers building their own low level routines have access to the same constants.
+
+use crate::error::Error;
+
+/// Fonts available for printing
+///
+/// Not every printer implements every font. The amount of columns each font can
+/// fit is stored in the [PrinterProfile](crate::PrinterProfile).
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Font {
+    /// Font A, usually the widest one (and the default)
+    FontA,
+    /// Font B, usually narrower than font A
+    FontB,
+    /// Font C, present only in a handful of models
+    FontC,
+}
+
+impl Font {
+    /// Byte selecting this font through the `ESC M n` command
+    pub fn as_byte(&self) -> u8 {
+        match self {
+            Font::FontA => 0x00,
+            Font::FontB => 0x01,
+            Font::FontC => 0x02,
+        }
+    }
+}
+
+/// Raw esc/pos commands
+///
+/// Each variant knows how to turn itself into the bytes the printer expects
+/// through [Command::as_bytes](crate::command::Command::as_bytes).
+pub enum Command {
+    /// Initializes the printer, clearing the buffer and resetting modes (`ESC @`)
+    Reset,
+    /// Selects the font to print with (`ESC M n`)
+    SelectFont(Font),
+    /// Aligns the following content to the left (`ESC a 0`)
+    JustifyLeft,
+    /// Centers the following content (`ESC a 1`)
+    JustifyCenter,
+    /// Aligns the following content to the right (`ESC a 2`)
+    JustifyRight,
+    /// Feeds and cuts the paper (`GS V 1`)
+    Cut,
+    /// A single line feed (`LF`)
+    LineFeed,
+    /// Turns emphasis (bold) on or off (`ESC E n`)
+    Bold(bool),
+    /// Turns underline on or off (`ESC - n`)
+    Underline(bool),
+    /// Turns reverse (white-on-black) printing on or off (`GS B n`)
+    Reverse(bool),
+    /// Selects character width/height multipliers (`GS ! n`)
+    CharacterSize(u8),
+    /// Turns smoothing on or off (`GS b n`)
+    Smoothing(bool),
+}
+
+impl Command {
+    /// Bytes that make up this command
+    pub fn as_bytes(&self) -> Vec<u8> {
+        match self {
+            Command::Reset => vec![0x1b, 0x40],
+            Command::SelectFont(font) => vec![0x1b, 0x4d, font.as_byte()],
+            Command::JustifyLeft => vec![0x1b, 0x61, 0x00],
+            Command::JustifyCenter => vec![0x1b, 0x61, 0x01],
+            Command::JustifyRight => vec![0x1b, 0x61, 0x02],
+            Command::Cut => vec![0x1d, 0x56, 0x01],
+            Command::LineFeed => vec![0x0a],
+            Command::Bold(on) => vec![0x1b, 0x45, *on as u8],
+            Command::Underline(on) => vec![0x1b, 0x2d, *on as u8],
+            Command::Reverse(on) => vec![0x1d, 0x42, *on as u8],
+            Command::CharacterSize(n) => vec![0x1d, 0x21, *n],
+            Command::Smoothing(on) => vec![0x1d, 0x62, *on as u8],
+        }
+    }
+}
+
+/// QR code model, selected through `GS ( k ... 65`
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum QrModel {
+    /// Original model
+    Model1,
+    /// The widely supported model 2 (the usual default)
+    Model2,
+    /// Micro QR code
+    Micro,
+}
+
+impl QrModel {
+    fn as_byte(&self) -> u8 {
+        match self {
+            QrModel::Model1 => 49,
+            QrModel::Model2 => 50,
+            QrModel::Micro => 51,
+        }
+    }
+}
+
+/// QR code error correction level, selected through `GS ( k ... 69`
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum QrErrorCorrection {
+    /// Recovers roughly 7% of the symbol
+    L,
+    /// Recovers roughly 15% of the symbol
+    M,
+    /// Recovers roughly 25% of the symbol
+    Q,
+    /// Recovers roughly 30% of the symbol
+    H,
+}
+
+impl QrErrorCorrection {
+    fn as_byte(&self) -> u8 {
+        match self {
+            QrErrorCorrection::L => 48,
+            QrErrorCorrection::M => 49,
+            QrErrorCorrection::Q => 50,
+            QrErrorCorrection::H => 51,
+        }
+    }
+}
+
+/// Symbology used when printing a 1D barcode through `GS k`
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BarcodeSystem {
+    /// EAN13 (13 digit retail barcode)
+    Ean13,
+    /// CODE39
+    Code39,
+    /// CODE128
+    Code128,
+}
+
+impl BarcodeSystem {
+    /// `m` selector used by the `GS k m n d...` (function B) form
+    fn as_byte(&self) -> u8 {
+        match self {
+            BarcodeSystem::Ean13 => 67,
+            BarcodeSystem::Code39 => 69,
+            BarcodeSystem::Code128 => 73,
+        }
+    }
+}
+
+/// Where the human readable interpretation is printed, selected through `GS H`
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum HriPosition {
+    /// No human readable text
+    None,
+    /// Above the barcode
+    Above,
+    /// Below the barcode
+    Below,
+    /// Both above and below the barcode
+    Both,
+}
+
+impl HriPosition {
+    fn as_byte(&self) -> u8 {
+        match self {
+            HriPosition::None => 0,
+            HriPosition::Above => 1,
+            HriPosition::Below => 2,
+            HriPosition::Both => 3,
+        }
+    }
+}
+
+/// Builds the `GS ( k` sequence that stores and prints a QR code
+///
+/// The sequence selects the model, module size and error correction level, loads
+/// `data` into the symbol storage area and finally prints it. The store command
+/// carries a 16-bit length, so `data` may be at most `65532` bytes long;
+/// anything longer yields an [Error::OutOfRange](crate::Error::OutOfRange).
+pub fn qr_code(
+    model: QrModel,
+    module_size: u8,
+    error_correction: QrErrorCorrection,
+    data: &[u8],
+) -> Result<Vec<u8>, Error> {
+    // Store length is data.len() + 3 (the cn/fn/m prefix) and must fit in pL pH
+    let length = data.len() + 3;
+    if length > 0xffff {
+        return Err(Error::OutOfRange(format!(
+            "qr code payload of {} bytes exceeds the 65532 byte limit",
+            data.len()
+        )));
+    }
+    let mut bytes = Vec::new();
+    // Select the model: GS ( k 04 00 31 41 n1 n2
+    bytes.extend_from_slice(&[0x1d, 0x28, 0x6b, 0x04, 0x00, 0x31, 0x41, model.as_byte(), 0x00]);
+    // Module size: GS ( k 03 00 31 43 n
+    bytes.extend_from_slice(&[0x1d, 0x28, 0x6b, 0x03, 0x00, 0x31, 0x43, module_size]);
+    // Error correction: GS ( k 03 00 31 45 n
+    bytes.extend_from_slice(&[0x1d, 0x28, 0x6b, 0x03, 0x00, 0x31, 0x45, error_correction.as_byte()]);
+    // Store data: GS ( k pL pH 31 50 30 d...
+    bytes.extend_from_slice(&[
+        0x1d,
+        0x28,
+        0x6b,
+        (length & 0xff) as u8,
+        ((length >> 8) & 0xff) as u8,
+        0x31,
+        0x50,
+        0x30,
+    ]);
+    bytes.extend_from_slice(data);
+    // Print the symbol: GS ( k 03 00 31 51 30
+    bytes.extend_from_slice(&[0x1d, 0x28, 0x6b, 0x03, 0x00, 0x31, 0x51, 0x30]);
+    Ok(bytes)
+}
+
+/// Builds the `GS k` sequence that prints a 1D barcode
+///
+/// Sets the HRI position, height and module width, then emits the symbology's
+/// function B form (`GS k m n d...`). The length is a single byte, so `data` may
+/// be at most `255` bytes long; a longer payload yields an
+/// [Error::OutOfRange](crate::Error::OutOfRange) rather than being truncated into
+/// a corrupt barcode.
+pub fn barcode(
+    system: BarcodeSystem,
+    hri: HriPosition,
+    height: u8,
+    width: u8,
+    data: &[u8],
+) -> Result<Vec<u8>, Error> {
+    if data.len() > 0xff {
+        return Err(Error::OutOfRange(format!(
+            "barcode payload of {} bytes exceeds the 255 byte limit",
+            data.len()
+        )));
+    }
+    let mut bytes = Vec::new();
+    // HRI position: GS H n
+    bytes.extend_from_slice(&[0x1d, 0x48, hri.as_byte()]);
+    // Barcode height: GS h n
+    bytes.extend_from_slice(&[0x1d, 0x68, height]);
+    // Module width: GS w n
+    bytes.extend_from_slice(&[0x1d, 0x77, width]);
+    // Print the barcode: GS k m n d...
+    bytes.extend_from_slice(&[0x1d, 0x6b, system.as_byte(), data.len() as u8]);
+    bytes.extend_from_slice(data);
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn qr_code_stores_and_prints() {
+        let bytes = qr_code(QrModel::Model2, 3, QrErrorCorrection::M, b"hi").unwrap();
+        assert_eq!(
+            bytes,
+            vec![
+                0x1d, 0x28, 0x6b, 0x04, 0x00, 0x31, 0x41, 0x32, 0x00, // select model 2
+                0x1d, 0x28, 0x6b, 0x03, 0x00, 0x31, 0x43, 0x03, // module size 3
+                0x1d, 0x28, 0x6b, 0x03, 0x00, 0x31, 0x45, 0x31, // error correction M
+                0x1d, 0x28, 0x6b, 0x05, 0x00, 0x31, 0x50, 0x30, b'h', b'i', // store "hi"
+                0x1d, 0x28, 0x6b, 0x03, 0x00, 0x31, 0x51, 0x30, // print
+            ]
+        );
+    }
+
+    #[test]
+    fn qr_code_rejects_over_long_payload() {
+        let data = vec![0u8; 0xffff];
+        assert!(matches!(
+            qr_code(QrModel::Model2, 3, QrErrorCorrection::M, &data),
+            Err(Error::OutOfRange(_))
+        ));
+    }
+
+    #[test]
+    fn barcode_sets_modes_and_prints() {
+        let bytes = barcode(BarcodeSystem::Ean13, HriPosition::Below, 100, 2, b"12345").unwrap();
+        assert_eq!(
+            bytes,
+            vec![
+                0x1d, 0x48, 0x02, // HRI below
+                0x1d, 0x68, 100, // height
+                0x1d, 0x77, 0x02, // width
+                0x1d, 0x6b, 67, 0x05, b'1', b'2', b'3', b'4', b'5', // EAN13 "12345"
+            ]
+        );
+    }
+
+    #[test]
+    fn barcode_rejects_over_long_payload() {
+        let data = vec![b'0'; 256];
+        assert!(matches!(
+            barcode(BarcodeSystem::Code128, HriPosition::None, 100, 2, &data),
+            Err(Error::OutOfRange(_))
+        ));
+    }
+}
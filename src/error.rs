@@ -0,0 +1,48 @@
+use std::error;
+use std::fmt;
+
+/// Errors that might arise while talking to a printer
+#[derive(Debug)]
+pub enum Error {
+    /// An error bubbled up from the underlying libusb context
+    LibusbError(libusb::Error),
+    /// An io error occured while talking to a network printer or writing to a file
+    IoError(std::io::Error),
+    /// No printer matching the requested profile was found on the bus
+    PrinterNotFound,
+    /// The bulk output endpoint could not be determined for the device
+    NoBulkEndpoint,
+    /// A replacement token present in an instruction was not provided in the [PrintData](crate::PrintData)
+    MissingReplacement(String),
+    /// A value that should fit in the esc/pos argument range was out of bounds
+    OutOfRange(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::LibusbError(e) => write!(formatter, "libusb error: {}", e),
+            Error::IoError(e) => write!(formatter, "io error: {}", e),
+            Error::PrinterNotFound => write!(formatter, "no printer was found"),
+            Error::NoBulkEndpoint => write!(formatter, "no bulk output endpoint was found"),
+            Error::MissingReplacement(token) => {
+                write!(formatter, "no replacement was provided for \"{}\"", token)
+            }
+            Error::OutOfRange(detail) => write!(formatter, "value out of range: {}", detail),
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+impl From<libusb::Error> for Error {
+    fn from(e: libusb::Error) -> Self {
+        Error::LibusbError(e)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IoError(e)
+    }
+}
@@ -0,0 +1,231 @@
+use super::{Justification, PrintData, TextStyle};
+use crate::command::{self, BarcodeSystem, Command, Font, HriPosition, QrErrorCorrection, QrModel};
+use crate::error::Error;
+use std::collections::HashSet;
+
+/// A printable template
+///
+/// Instructions describe a piece of a document once and can then be rendered any
+/// number of times against different [PrintData](crate::PrintData), substituting
+/// the tokens declared in `replacements`.
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug)]
+pub enum Instruction {
+    /// A run of text with a font and justification
+    Text {
+        /// Content, possibly containing replacement tokens
+        content: String,
+        /// Font to print the text with
+        font: Font,
+        /// Alignment of the text
+        justification: Justification,
+        /// Inline formatting wrapped around the text
+        style: TextStyle,
+        /// Tokens in `content` that must be provided through the print data
+        replacements: Option<HashSet<String>>,
+    },
+    /// A QR code
+    QrCode {
+        /// Payload, possibly containing replacement tokens (for example a URL)
+        data: String,
+        /// QR code model
+        model: QrModel,
+        /// Module size in dots
+        module_size: u8,
+        /// Error correction level
+        error_correction: QrErrorCorrection,
+        /// Tokens in `data` that must be provided through the print data
+        replacements: Option<HashSet<String>>,
+    },
+    /// A 1D barcode
+    Barcode {
+        /// Payload, possibly containing replacement tokens (for example an order number)
+        data: String,
+        /// Symbology to encode the payload with
+        system: BarcodeSystem,
+        /// Where the human readable interpretation is printed
+        hri: HriPosition,
+        /// Barcode height in dots
+        height: u8,
+        /// Module width
+        width: u8,
+        /// Tokens in `data` that must be provided through the print data
+        replacements: Option<HashSet<String>>,
+    },
+}
+
+impl Instruction {
+    /// Builds a text instruction
+    pub fn text<A: Into<String>>(
+        content: A,
+        font: Font,
+        justification: Justification,
+        style: TextStyle,
+        replacements: Option<HashSet<String>>,
+    ) -> Instruction {
+        Instruction::Text {
+            content: content.into(),
+            font,
+            justification,
+            style,
+            replacements,
+        }
+    }
+
+    /// Builds a QR code instruction
+    pub fn qr_code<A: Into<String>>(
+        data: A,
+        model: QrModel,
+        module_size: u8,
+        error_correction: QrErrorCorrection,
+        replacements: Option<HashSet<String>>,
+    ) -> Instruction {
+        Instruction::QrCode {
+            data: data.into(),
+            model,
+            module_size,
+            error_correction,
+            replacements,
+        }
+    }
+
+    /// Builds a 1D barcode instruction
+    pub fn barcode<A: Into<String>>(
+        data: A,
+        system: BarcodeSystem,
+        hri: HriPosition,
+        height: u8,
+        width: u8,
+        replacements: Option<HashSet<String>>,
+    ) -> Instruction {
+        Instruction::Barcode {
+            data: data.into(),
+            system,
+            hri,
+            height,
+            width,
+            replacements,
+        }
+    }
+
+    /// Renders this instruction against the given print data into esc/pos bytes
+    pub fn to_bytes(&self, print_data: Option<&PrintData>) -> Result<Vec<u8>, Error> {
+        match self {
+            Instruction::Text {
+                content,
+                font,
+                justification,
+                style,
+                replacements,
+            } => {
+                let content = Instruction::substitute(content, replacements, print_data)?;
+                let mut bytes = Command::SelectFont(*font).as_bytes();
+                bytes.extend(justification.as_command().as_bytes());
+                bytes.extend(style.prelude());
+                bytes.extend_from_slice(content.as_bytes());
+                bytes.extend(style.epilogue());
+                bytes.extend(Command::LineFeed.as_bytes());
+                Ok(bytes)
+            }
+            Instruction::QrCode {
+                data,
+                model,
+                module_size,
+                error_correction,
+                replacements,
+            } => {
+                let data = Instruction::substitute(data, replacements, print_data)?;
+                command::qr_code(*model, *module_size, *error_correction, data.as_bytes())
+            }
+            Instruction::Barcode {
+                data,
+                system,
+                hri,
+                height,
+                width,
+                replacements,
+            } => {
+                let data = Instruction::substitute(data, replacements, print_data)?;
+                command::barcode(*system, *hri, *height, *width, data.as_bytes())
+            }
+        }
+    }
+
+    /// Applies the declared replacements to a piece of content
+    fn substitute(
+        content: &str,
+        replacements: &Option<HashSet<String>>,
+        print_data: Option<&PrintData>,
+    ) -> Result<String, Error> {
+        let mut content = content.to_string();
+        if let Some(replacements) = replacements {
+            for token in replacements {
+                let value = print_data
+                    .and_then(|data| data.replacements.get(token))
+                    .ok_or_else(|| Error::MissingReplacement(token.clone()))?;
+                content = content.replace(token, value);
+            }
+        }
+        Ok(content)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn text_wraps_content_in_font_justification_and_style() {
+        let instruction = Instruction::text(
+            "Hi",
+            Font::FontA,
+            Justification::Left,
+            TextStyle::default(),
+            None,
+        );
+        assert_eq!(
+            instruction.to_bytes(None).unwrap(),
+            vec![
+                0x1b, 0x4d, 0x00, // select font A
+                0x1b, 0x61, 0x00, // justify left
+                0x1b, 0x45, 0x00, 0x1b, 0x2d, 0x00, 0x1d, 0x42, 0x00, 0x1d, 0x62, 0x00, 0x1d,
+                0x21, 0x00, // style prelude (defaults)
+                b'H', b'i', //
+                0x1b, 0x45, 0x00, 0x1b, 0x2d, 0x00, 0x1d, 0x42, 0x00, 0x1d, 0x62, 0x00, 0x1d,
+                0x21, 0x00, // style epilogue (reset)
+                0x0a, // line feed
+            ]
+        );
+    }
+
+    #[test]
+    fn text_substitutes_declared_tokens() {
+        let replacements = Some(vec!["%name%".to_string()].into_iter().collect());
+        let instruction = Instruction::text(
+            "%name%",
+            Font::FontA,
+            Justification::Left,
+            TextStyle::default(),
+            replacements,
+        );
+        let print_data = PrintData::builder().replacement("%name%", "Bob").build();
+        let bytes = instruction.to_bytes(Some(&print_data)).unwrap();
+        assert!(bytes.windows(3).any(|window| window == b"Bob"));
+    }
+
+    #[test]
+    fn missing_replacement_is_reported() {
+        let replacements = Some(vec!["%name%".to_string()].into_iter().collect());
+        let instruction = Instruction::text(
+            "%name%",
+            Font::FontA,
+            Justification::Left,
+            TextStyle::default(),
+            replacements,
+        );
+        assert!(matches!(
+            instruction.to_bytes(None),
+            Err(Error::MissingReplacement(_))
+        ));
+    }
+}
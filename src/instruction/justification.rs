@@ -1,11 +1,21 @@
-extern crate serde;
-
-use serde::{Deserialize, Serialize};
+use crate::command::Command;
 
 /// Alignment for text printing
-#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug)]
 pub enum Justification {
     Left,
     Center,
     Right,
 }
+
+impl Justification {
+    /// esc/pos command that selects this alignment
+    pub fn as_command(&self) -> Command {
+        match self {
+            Justification::Left => Command::JustifyLeft,
+            Justification::Center => Command::JustifyCenter,
+            Justification::Right => Command::JustifyRight,
+        }
+    }
+}
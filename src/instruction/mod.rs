@@ -0,0 +1,9 @@
+mod instruction;
+mod justification;
+mod print_data;
+mod text_style;
+
+pub use instruction::Instruction;
+pub use justification::Justification;
+pub use print_data::{PrintData, PrintDataBuilder};
+pub use text_style::TextStyle;
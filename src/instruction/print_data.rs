@@ -0,0 +1,46 @@
+use std::collections::HashMap;
+
+/// Per-print data fed to an [Instruction](crate::Instruction)
+///
+/// A single instruction acts as a template; the `PrintData` carries the values
+/// that get substituted for this particular print (for example the customer name
+/// or an order number).
+#[derive(Clone, Debug, Default)]
+pub struct PrintData {
+    pub(crate) replacements: HashMap<String, String>,
+}
+
+impl PrintData {
+    /// Creates a [builder](crate::PrintDataBuilder) for the print data
+    pub fn builder() -> PrintDataBuilder {
+        PrintDataBuilder::new()
+    }
+}
+
+/// Helper structure to create a [PrintData](crate::PrintData)
+#[derive(Default)]
+pub struct PrintDataBuilder {
+    replacements: HashMap<String, String>,
+}
+
+impl PrintDataBuilder {
+    /// Creates a new, empty builder
+    pub fn new() -> PrintDataBuilder {
+        PrintDataBuilder {
+            replacements: HashMap::new(),
+        }
+    }
+
+    /// Registers a replacement, substituting `token` for `value` at print time
+    pub fn replacement<A: Into<String>, B: Into<String>>(mut self, token: A, value: B) -> Self {
+        self.replacements.insert(token.into(), value.into());
+        self
+    }
+
+    /// Builds the final [PrintData](crate::PrintData)
+    pub fn build(self) -> PrintData {
+        PrintData {
+            replacements: self.replacements,
+        }
+    }
+}
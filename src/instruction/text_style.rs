@@ -0,0 +1,149 @@
+use crate::command::Command;
+
+/// Inline formatting applied to a text [Instruction](crate::Instruction)
+///
+/// A style is emitted right before the substituted text and the affected modes
+/// are reset to their defaults right after, so the formatting never leaks into
+/// later lines. Width and height are 1-based multipliers in the `1..=8` range.
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug)]
+pub struct TextStyle {
+    bold: bool,
+    underline: bool,
+    reverse: bool,
+    smoothing: bool,
+    width: u8,
+    height: u8,
+}
+
+impl Default for TextStyle {
+    fn default() -> Self {
+        TextStyle {
+            bold: false,
+            underline: false,
+            reverse: false,
+            smoothing: false,
+            width: 1,
+            height: 1,
+        }
+    }
+}
+
+impl TextStyle {
+    /// Starts from the default (plain) style
+    pub fn new() -> TextStyle {
+        TextStyle::default()
+    }
+
+    /// Prints the text in bold (emphasis)
+    pub fn bold(mut self, bold: bool) -> Self {
+        self.bold = bold;
+        self
+    }
+
+    /// Underlines the text
+    pub fn underline(mut self, underline: bool) -> Self {
+        self.underline = underline;
+        self
+    }
+
+    /// Prints the text white-on-black
+    pub fn reverse(mut self, reverse: bool) -> Self {
+        self.reverse = reverse;
+        self
+    }
+
+    /// Enables smoothing for scaled up text
+    pub fn smoothing(mut self, smoothing: bool) -> Self {
+        self.smoothing = smoothing;
+        self
+    }
+
+    /// Sets the width and height multipliers, clamped to the `1..=8` range
+    pub fn size(mut self, width: u8, height: u8) -> Self {
+        self.width = width.max(1).min(8);
+        self.height = height.max(1).min(8);
+        self
+    }
+
+    /// `n` argument of the `GS ! n` character size command
+    ///
+    /// The multipliers are clamped to the `1..=8` range here as well, so a style
+    /// deserialized straight from config (which bypasses [size](TextStyle::size))
+    /// can never underflow or select an out-of-range multiplier.
+    fn size_byte(&self) -> u8 {
+        let width = self.width.max(1).min(8);
+        let height = self.height.max(1).min(8);
+        ((width - 1) << 4) | (height - 1)
+    }
+
+    /// Commands that turn the style on, emitted before the text
+    pub(crate) fn prelude(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend(Command::Bold(self.bold).as_bytes());
+        bytes.extend(Command::Underline(self.underline).as_bytes());
+        bytes.extend(Command::Reverse(self.reverse).as_bytes());
+        bytes.extend(Command::Smoothing(self.smoothing).as_bytes());
+        bytes.extend(Command::CharacterSize(self.size_byte()).as_bytes());
+        bytes
+    }
+
+    /// Commands that restore the defaults, emitted after the text
+    pub(crate) fn epilogue(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend(Command::Bold(false).as_bytes());
+        bytes.extend(Command::Underline(false).as_bytes());
+        bytes.extend(Command::Reverse(false).as_bytes());
+        bytes.extend(Command::Smoothing(false).as_bytes());
+        bytes.extend(Command::CharacterSize(0).as_bytes());
+        bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn size_byte_clamps_out_of_range_fields() {
+        // Fields bypassing `size()` (e.g. from Deserialize) must not panic
+        let style = TextStyle {
+            bold: false,
+            underline: false,
+            reverse: false,
+            smoothing: false,
+            width: 0,
+            height: 9,
+        };
+        assert_eq!(style.size_byte(), 0x07);
+    }
+
+    #[test]
+    fn default_style_resets_every_mode() {
+        let style = TextStyle::default();
+        let reset = vec![
+            0x1b, 0x45, 0x00, // bold off
+            0x1b, 0x2d, 0x00, // underline off
+            0x1d, 0x42, 0x00, // reverse off
+            0x1d, 0x62, 0x00, // smoothing off
+            0x1d, 0x21, 0x00, // size 1x1
+        ];
+        assert_eq!(style.prelude(), reset);
+        assert_eq!(style.epilogue(), reset);
+    }
+
+    #[test]
+    fn prelude_enables_selected_modes() {
+        let style = TextStyle::new().bold(true).size(2, 3);
+        assert_eq!(
+            style.prelude(),
+            vec![
+                0x1b, 0x45, 0x01, // bold on
+                0x1b, 0x2d, 0x00, // underline off
+                0x1d, 0x42, 0x00, // reverse off
+                0x1d, 0x62, 0x00, // smoothing off
+                0x1d, 0x21, 0x12, // width 2, height 3
+            ]
+        );
+    }
+}
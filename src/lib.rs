@@ -3,14 +3,14 @@
 //! For printing, a libusb [Context](https://docs.rs/libusb/0.3.0/libusb/struct.Context.html) is required.
 //!
 //! ```rust
-//! use escpos_rs::{Printer};
+//! use escpos_rs::{Printer, PrinterModel};
 //! use libusb::{Context};
 //!
 //! fn main() {
 //!     // We create a usb contest for the printer
 //!     let context = Context::new().unwrap();
 //!     // We pass it to the printer
-//!     let printer = match Printer::with_context(&context, PrinterModel::TMT20.details()) {
+//!     let printer = match Printer::with_context(&context, PrinterModel::TMT20.usb_profile()) {
 //!         Ok(maybe_printer) => match maybe_printer {
 //!             Some(printer) => printer,
 //!             None => panic!("No printer was found :(")
@@ -29,7 +29,7 @@
 //!
 //! ## Printer Details
 //!
-//! In order to print, some data about the printer must be known. The [PrinterDetails](crate::PrinterDetails) structure fulfills this purpose.
+//! In order to print, some data about the printer must be known. The [PrinterProfile](crate::PrinterProfile) structure fulfills this purpose.
 //!
 //! The strict minimum information needed to print, are the vendor id, the product id. Both vendor and product id should be found in the maker's website, or sometimes they get printed in test prints (which usually occur if you hold the feed button on the printer).
 //!
@@ -41,20 +41,20 @@
 //!
 //! ```rust
 //! use escpos_rs::{
-//!     Printer, PrintData, PrinterDetails,
-//!     Instruction, Justification, command::Font
+//!     Printer, PrintData, PrinterProfile,
+//!     Instruction, Justification, TextStyle, command::Font
 //! };
 //! use libusb::{Context};
-//! 
+//!
 //! fn main() {
 //!     // We create a usb contest for the printer
 //!     let context = Context::new().unwrap();
-//!     // Printer details...
-//!     let printer_details = PrinterDetails::builder(0x0001, 0x0001)
+//!     // Printer profile...
+//!     let printer_profile = PrinterProfile::usb_builder(0x0001, 0x0001)
 //!         .with_font_width(Font::FontA, 32)
 //!         .build();
 //!     // We pass it to the printer
-//!     let printer = match Printer::with_context(&context, printer_details) {
+//!     let printer = match Printer::with_context(&context, printer_profile) {
 //!         Ok(maybe_printer) => match maybe_printer {
 //!             Some(printer) => printer,
 //!             None => panic!("No printer was found :(")
@@ -66,7 +66,8 @@
 //!         "Hello, %name%!",
 //!         Font::FontA,
 //!         Justification::Center,
-//!         /// Words that will be replaced in this specific instruction
+//!         TextStyle::default(),
+//!         // Words that will be replaced in this specific instruction
 //!         Some(vec!["%name%".into()].into_iter().collect())
 //!     );
 //!     // We create custom information for the instruction
@@ -91,8 +92,11 @@
 //! }
 //! ```
 
-pub use printer::{Printer, PrinterDetails, PrinterDetailsBuilder, PrinterModel};
-pub use instruction::{Instruction, Justification, PrintData, PrintDataBuilder};
+pub use printer::{
+    detect, DebugDriver, DetectedPrinter, Driver, FileDriver, NetworkDriver, Printer,
+    PrinterConnectionData, PrinterModel, PrinterProfile, PrinterProfileBuilder, UsbDriver,
+};
+pub use instruction::{Instruction, Justification, PrintData, PrintDataBuilder, TextStyle};
 pub use error::{Error};
 
 /// Contains raw esc/pos commands
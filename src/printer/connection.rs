@@ -0,0 +1,31 @@
+use std::time::Duration;
+
+/// Ways in which a printer can be reached
+///
+/// A [PrinterProfile](crate::PrinterProfile) carries one of these so that
+/// [Printer::with_context](crate::Printer::with_context) knows whether to open a
+/// libusb endpoint or a raw `TcpStream`.
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug)]
+pub enum PrinterConnectionData {
+    /// A printer attached through USB, reached through libusb
+    Usb {
+        /// Vendor id of the printer
+        vendor_id: u16,
+        /// Product id of the printer
+        product_id: u16,
+        /// Bulk output endpoint. If `None`, the first bulk-OUT endpoint is used
+        endpoint: Option<u8>,
+        /// Timeout for bulk writes
+        timeout: Duration,
+    },
+    /// A printer listening on a raw (JetDirect) tcp port, usually 9100
+    Network {
+        /// Host name or ip address of the printer
+        host: String,
+        /// Port the printer listens on (9100 for most ethernet/wi-fi models)
+        port: u16,
+        /// Timeout applied to both the connection and the writes
+        timeout: Duration,
+    },
+}
@@ -0,0 +1,141 @@
+use super::{PrinterConnectionData, PrinterProfile};
+use crate::error::Error;
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// USB base class reserved for printers (`bInterfaceClass = 7`)
+const PRINTER_INTERFACE_CLASS: u8 = 0x07;
+/// Printer subclass every esc/pos device exposes (`bInterfaceSubclass = 1`)
+const PRINTER_INTERFACE_SUBCLASS: u8 = 0x01;
+
+/// A printer found on the bus through [detect](crate::detect)
+///
+/// The `id` is stable across reconnects: it is derived from the device's string
+/// descriptors rather than its (volatile) bus address, so the same physical
+/// printer keeps the same identifier even when the OS reassigns it.
+#[derive(Clone, Debug)]
+pub struct DetectedPrinter {
+    /// Stable identifier for the physical printer
+    pub id: String,
+    /// Profile ready to be handed to [Printer::with_context](crate::Printer::with_context)
+    pub profile: PrinterProfile,
+}
+
+/// Enumerates the libusb context and returns every esc/pos printer attached
+///
+/// Devices are recognised by the USB printer interface class rather than a
+/// hard-coded vendor/product table, and the bulk-OUT endpoint is read straight
+/// from the matching interface descriptor, so callers do not need to know any
+/// ids or endpoints in advance.
+pub fn detect(context: &libusb::Context) -> Result<Vec<DetectedPrinter>, Error> {
+    let timeout = Duration::from_secs(1);
+    let mut detected = Vec::new();
+    for device in context.devices()?.iter() {
+        let device_descriptor = device.device_descriptor()?;
+        let config = match device.active_config_descriptor() {
+            Ok(config) => config,
+            Err(_) => continue,
+        };
+        // Look for an interface advertising the printer class and grab its bulk-OUT endpoint
+        let mut endpoint = None;
+        for interface in config.interfaces() {
+            for descriptor in interface.descriptors() {
+                if descriptor.class_code() != PRINTER_INTERFACE_CLASS
+                    || descriptor.sub_class_code() != PRINTER_INTERFACE_SUBCLASS
+                {
+                    continue;
+                }
+                for endpoint_descriptor in descriptor.endpoint_descriptors() {
+                    if endpoint_descriptor.direction() == libusb::Direction::Out
+                        && endpoint_descriptor.transfer_type() == libusb::TransferType::Bulk
+                    {
+                        endpoint = Some(endpoint_descriptor.address());
+                        break;
+                    }
+                }
+            }
+            if endpoint.is_some() {
+                break;
+            }
+        }
+        let endpoint = match endpoint {
+            Some(endpoint) => endpoint,
+            None => continue,
+        };
+
+        let vendor_id = device_descriptor.vendor_id();
+        let product_id = device_descriptor.product_id();
+        let id = stable_id(&device, &device_descriptor, vendor_id, product_id, timeout);
+
+        detected.push(DetectedPrinter {
+            id,
+            profile: PrinterProfile {
+                printer_connection_data: PrinterConnectionData::Usb {
+                    vendor_id,
+                    product_id,
+                    endpoint: Some(endpoint),
+                    timeout: Duration::from_secs(2),
+                },
+                columns_per_font: HashMap::new(),
+                width: 384,
+            },
+        });
+    }
+    Ok(detected)
+}
+
+/// Builds a reconnect-stable identifier for a device
+///
+/// The manufacturer, product and serial string descriptors are concatenated and
+/// hashed into a 128-bit digest rendered as hex. When no serial string is
+/// present we fall back to `vendor_id:product_id`, which is stable per model but
+/// not per unit.
+fn stable_id(
+    device: &libusb::Device,
+    device_descriptor: &libusb::DeviceDescriptor,
+    vendor_id: u16,
+    product_id: u16,
+    timeout: Duration,
+) -> String {
+    let fallback = || format!("{:04x}:{:04x}", vendor_id, product_id);
+    let handle = match device.open() {
+        Ok(handle) => handle,
+        Err(_) => return fallback(),
+    };
+    let language = match handle.read_languages(timeout) {
+        Ok(languages) => match languages.into_iter().next() {
+            Some(language) => language,
+            None => return fallback(),
+        },
+        Err(_) => return fallback(),
+    };
+    let manufacturer = handle
+        .read_manufacturer_string(language, device_descriptor, timeout)
+        .unwrap_or_default();
+    let product = handle
+        .read_product_string(language, device_descriptor, timeout)
+        .unwrap_or_default();
+    let serial = handle
+        .read_serial_number_string(language, device_descriptor, timeout)
+        .unwrap_or_default();
+    if serial.is_empty() {
+        return fallback();
+    }
+    let seed = format!("{}{}{}", manufacturer, product, serial);
+    format!("{:032x}", digest_128(seed.as_bytes()))
+}
+
+/// 128-bit FNV-1a digest of the given bytes
+///
+/// A dependency-free hash is enough here: we only need the same input to map to
+/// the same identifier, not cryptographic strength.
+fn digest_128(bytes: &[u8]) -> u128 {
+    const OFFSET: u128 = 0x6c62272e07bb014262b821756295c58d;
+    const PRIME: u128 = 0x0000000001000000000000000000013b;
+    let mut hash = OFFSET;
+    for byte in bytes {
+        hash ^= *byte as u128;
+        hash = hash.wrapping_mul(PRIME);
+    }
+    hash
+}
@@ -0,0 +1,180 @@
+use crate::error::Error;
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::net::TcpStream;
+use std::path::Path;
+use std::time::Duration;
+
+/// Anything a [Printer](crate::Printer) can push esc/pos bytes to
+///
+/// Abstracting the sink behind this trait keeps the rendering logic independent
+/// of the hardware: the same bytes can reach a libusb endpoint, a file, or an
+/// in-memory buffer, which makes it possible to assert the exact sequences a
+/// template produces before sending them to a real printer.
+pub trait Driver {
+    /// Pushes a chunk of bytes towards the sink
+    fn write(&mut self, data: &[u8]) -> Result<(), Error>;
+    /// Flushes any buffered bytes
+    fn flush(&mut self) -> Result<(), Error>;
+}
+
+/// Driver writing to a libusb bulk-OUT endpoint
+pub struct UsbDriver<'a> {
+    device_handle: libusb::DeviceHandle<'a>,
+    endpoint: u8,
+    timeout: Duration,
+}
+
+impl<'a> UsbDriver<'a> {
+    /// Wraps an already opened device handle
+    pub fn new(device_handle: libusb::DeviceHandle<'a>, endpoint: u8, timeout: Duration) -> Self {
+        UsbDriver {
+            device_handle,
+            endpoint,
+            timeout,
+        }
+    }
+}
+
+impl<'a> Driver for UsbDriver<'a> {
+    fn write(&mut self, data: &[u8]) -> Result<(), Error> {
+        self.device_handle.write_bulk(self.endpoint, data, self.timeout)?;
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+/// Driver writing to a raw (JetDirect) tcp stream
+pub struct NetworkDriver {
+    stream: TcpStream,
+}
+
+impl NetworkDriver {
+    /// Wraps an already connected tcp stream
+    pub fn new(stream: TcpStream) -> Self {
+        NetworkDriver { stream }
+    }
+}
+
+impl Driver for NetworkDriver {
+    fn write(&mut self, data: &[u8]) -> Result<(), Error> {
+        self.stream.write_all(data)?;
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<(), Error> {
+        self.stream.flush()?;
+        Ok(())
+    }
+}
+
+/// Driver dumping the byte stream to a file, handy for spooler/CUPS forwarding
+pub struct FileDriver {
+    file: File,
+}
+
+impl FileDriver {
+    /// Opens (creating or truncating) the file at `path`
+    pub fn new<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let file = OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(path)?;
+        Ok(FileDriver { file })
+    }
+}
+
+impl Driver for FileDriver {
+    fn write(&mut self, data: &[u8]) -> Result<(), Error> {
+        self.file.write_all(data)?;
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<(), Error> {
+        self.file.flush()?;
+        Ok(())
+    }
+}
+
+/// An in-memory driver, collecting everything written into a `Vec<u8>`
+///
+/// Useful in tests to capture exactly which esc/pos sequences an
+/// [Instruction](crate::Instruction) produces.
+impl Driver for Vec<u8> {
+    fn write(&mut self, data: &[u8]) -> Result<(), Error> {
+        self.extend_from_slice(data);
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+/// Wraps another driver and dumps every emitted chunk in hex and decimal
+///
+/// The underlying driver still receives the bytes, so a `DebugDriver<FileDriver>`
+/// both records and traces the output.
+pub struct DebugDriver<D: Driver> {
+    inner: D,
+}
+
+impl<D: Driver> DebugDriver<D> {
+    /// Wraps `inner`, tracing everything that flows through it
+    pub fn new(inner: D) -> Self {
+        DebugDriver { inner }
+    }
+
+    /// Consumes the wrapper, returning the underlying driver
+    pub fn into_inner(self) -> D {
+        self.inner
+    }
+}
+
+impl<D: Driver> Driver for DebugDriver<D> {
+    fn write(&mut self, data: &[u8]) -> Result<(), Error> {
+        let hex = data
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let dec = data
+            .iter()
+            .map(|byte| byte.to_string())
+            .collect::<Vec<_>>()
+            .join(" ");
+        println!("hex: {}", hex);
+        println!("dec: {}", dec);
+        self.inner.write(data)
+    }
+
+    fn flush(&mut self) -> Result<(), Error> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn in_memory_driver_collects_every_write() {
+        let mut driver: Vec<u8> = Vec::new();
+        Driver::write(&mut driver, b"esc").unwrap();
+        Driver::write(&mut driver, b"/pos").unwrap();
+        Driver::flush(&mut driver).unwrap();
+        assert_eq!(driver, b"esc/pos".to_vec());
+    }
+
+    #[test]
+    fn debug_driver_forwards_to_its_inner_driver() {
+        let mut driver = DebugDriver::new(Vec::<u8>::new());
+        driver.write(&[0x1b, 0x40]).unwrap();
+        driver.flush().unwrap();
+        assert_eq!(driver.into_inner(), vec![0x1b, 0x40]);
+    }
+}
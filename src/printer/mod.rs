@@ -0,0 +1,13 @@
+mod connection;
+mod detect;
+mod driver;
+mod printer;
+mod printer_model;
+mod profile;
+
+pub use connection::PrinterConnectionData;
+pub use detect::{detect, DetectedPrinter};
+pub use driver::{DebugDriver, Driver, FileDriver, NetworkDriver, UsbDriver};
+pub use printer::Printer;
+pub use printer_model::PrinterModel;
+pub use profile::{PrinterProfile, PrinterProfileBuilder};
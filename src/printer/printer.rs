@@ -0,0 +1,171 @@
+use super::driver::{Driver, NetworkDriver, UsbDriver};
+use super::{PrinterConnectionData, PrinterProfile};
+use crate::command::Command;
+use crate::error::Error;
+use crate::instruction::{Instruction, PrintData};
+use std::cell::RefCell;
+use std::net::{TcpStream, ToSocketAddrs};
+
+/// Main structure, representing a connected thermal printer
+///
+/// The byte sink lives behind a [Driver](crate::Driver), so besides real usb and
+/// network printers a `Printer` can also be built around a file or an in-memory
+/// buffer for testing.
+pub struct Printer<'a> {
+    printer_profile: PrinterProfile,
+    driver: RefCell<Box<dyn Driver + 'a>>,
+}
+
+impl<'a> Printer<'a> {
+    /// Connects to a printer described by the given [PrinterProfile](crate::PrinterProfile)
+    ///
+    /// The `context` is only consulted for usb printers; network profiles open a
+    /// `TcpStream` and ignore it. `Ok(None)` is returned when the profile points
+    /// at usb but no matching device is present on the bus.
+    pub fn with_context(
+        context: &'a libusb::Context,
+        printer_profile: PrinterProfile,
+    ) -> Result<Option<Printer<'a>>, Error> {
+        let driver: Box<dyn Driver + 'a> = match &printer_profile.printer_connection_data {
+            PrinterConnectionData::Usb {
+                vendor_id,
+                product_id,
+                endpoint,
+                timeout,
+            } => {
+                let devices = context.devices()?;
+                let mut found = None;
+                for device in devices.iter() {
+                    let descriptor = device.device_descriptor()?;
+                    if descriptor.vendor_id() == *vendor_id
+                        && descriptor.product_id() == *product_id
+                    {
+                        found = Some(device);
+                        break;
+                    }
+                }
+                let device = match found {
+                    Some(device) => device,
+                    None => return Ok(None),
+                };
+                let endpoint = match endpoint {
+                    Some(endpoint) => *endpoint,
+                    None => Printer::bulk_out_endpoint(&device)?,
+                };
+                Box::new(UsbDriver::new(device.open()?, endpoint, *timeout))
+            }
+            PrinterConnectionData::Network {
+                host,
+                port,
+                timeout,
+            } => {
+                let mut last_error = None;
+                let mut stream = None;
+                for address in (host.as_str(), *port).to_socket_addrs()? {
+                    match TcpStream::connect_timeout(&address, *timeout) {
+                        Ok(tcp) => {
+                            tcp.set_write_timeout(Some(*timeout))?;
+                            stream = Some(tcp);
+                            break;
+                        }
+                        Err(e) => last_error = Some(e),
+                    }
+                }
+                match stream {
+                    Some(stream) => Box::new(NetworkDriver::new(stream)),
+                    None => {
+                        return Err(last_error
+                            .unwrap_or_else(|| {
+                                std::io::Error::new(
+                                    std::io::ErrorKind::NotFound,
+                                    "could not resolve printer host",
+                                )
+                            })
+                            .into())
+                    }
+                }
+            }
+        };
+        Ok(Some(Printer {
+            printer_profile,
+            driver: RefCell::new(driver),
+        }))
+    }
+
+    /// Builds a printer around an arbitrary [Driver](crate::Driver)
+    ///
+    /// This is the entry point for capturing output, for example with a
+    /// `Vec<u8>` or a [FileDriver](crate::FileDriver), without touching hardware.
+    pub fn new<D: Driver + 'a>(printer_profile: PrinterProfile, driver: D) -> Printer<'a> {
+        Printer {
+            printer_profile,
+            driver: RefCell::new(Box::new(driver)),
+        }
+    }
+
+    /// Picks the first bulk-OUT endpoint advertised by the device
+    fn bulk_out_endpoint(device: &libusb::Device<'a>) -> Result<u8, Error> {
+        let config = device.active_config_descriptor()?;
+        for interface in config.interfaces() {
+            for descriptor in interface.descriptors() {
+                for endpoint in descriptor.endpoint_descriptors() {
+                    if endpoint.direction() == libusb::Direction::Out
+                        && endpoint.transfer_type() == libusb::TransferType::Bulk
+                    {
+                        return Ok(endpoint.address());
+                    }
+                }
+            }
+        }
+        Err(Error::NoBulkEndpoint)
+    }
+
+    /// The profile this printer was built from
+    pub fn profile(&self) -> &PrinterProfile {
+        &self.printer_profile
+    }
+
+    /// Sends raw bytes to the printer
+    pub fn raw<A: AsRef<[u8]>>(&self, bytes: A) -> Result<(), Error> {
+        let mut driver = self.driver.borrow_mut();
+        driver.write(bytes.as_ref())?;
+        driver.flush()
+    }
+
+    /// Renders an instruction against the given print data and sends it
+    ///
+    /// The instruction is turned into esc/pos bytes through
+    /// [Instruction::to_bytes](crate::Instruction::to_bytes) and written through
+    /// the printer's [Driver](crate::Driver), so the very same call captures
+    /// output when the printer is backed by a `Vec<u8>` or a
+    /// [FileDriver](crate::FileDriver).
+    pub fn instruction(
+        &self,
+        instruction: &Instruction,
+        print_data: &PrintData,
+    ) -> Result<(), Error> {
+        let bytes = instruction.to_bytes(Some(print_data))?;
+        self.raw(bytes)
+    }
+
+    /// Renders and sends a batch of instructions in order against the same data
+    pub fn instructions(
+        &self,
+        instructions: &[Instruction],
+        print_data: &PrintData,
+    ) -> Result<(), Error> {
+        for instruction in instructions {
+            self.instruction(instruction, print_data)?;
+        }
+        Ok(())
+    }
+
+    /// Prints a string, feeding and cutting the paper afterwards
+    pub fn print<A: AsRef<str>>(&self, content: A) -> Result<(), Error> {
+        let mut bytes = Command::Reset.as_bytes();
+        bytes.extend_from_slice(content.as_ref().as_bytes());
+        bytes.extend(Command::LineFeed.as_bytes());
+        bytes.extend(Command::Cut.as_bytes());
+        self.raw(bytes)
+    }
+}
@@ -0,0 +1,105 @@
+use super::PrinterConnectionData;
+use crate::command::Font;
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// Details required to connect and print to a thermal printer
+///
+/// Besides the connection data, the profile records how many columns each font
+/// is able to fit and the width of the printer head in dots.
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug)]
+pub struct PrinterProfile {
+    /// How the printer is reached (usb or network)
+    pub printer_connection_data: PrinterConnectionData,
+    /// Columns that fit in a line for each known font
+    pub columns_per_font: HashMap<Font, u8>,
+    /// Width of the printer head in dots
+    pub width: u16,
+}
+
+impl PrinterProfile {
+    /// Creates a [builder](crate::PrinterProfileBuilder) for a usb printer
+    pub fn usb_builder(vendor_id: u16, product_id: u16) -> PrinterProfileBuilder {
+        PrinterProfileBuilder::new_usb(vendor_id, product_id)
+    }
+
+    /// Creates a [builder](crate::PrinterProfileBuilder) for a network printer
+    pub fn network_builder<A: Into<String>>(host: A, port: u16) -> PrinterProfileBuilder {
+        PrinterProfileBuilder::new_network(host, port)
+    }
+}
+
+/// Helper structure to create a [PrinterProfile](crate::PrinterProfile)
+pub struct PrinterProfileBuilder {
+    printer_connection_data: PrinterConnectionData,
+    columns_per_font: HashMap<Font, u8>,
+    width: u16,
+}
+
+impl PrinterProfileBuilder {
+    /// Creates a new builder for a printer reached through usb
+    pub fn new_usb(vendor_id: u16, product_id: u16) -> PrinterProfileBuilder {
+        PrinterProfileBuilder {
+            printer_connection_data: PrinterConnectionData::Usb {
+                vendor_id,
+                product_id,
+                endpoint: None,
+                timeout: Duration::from_secs(2),
+            },
+            columns_per_font: HashMap::new(),
+            width: 384,
+        }
+    }
+
+    /// Creates a new builder for a printer reached through a raw tcp port
+    pub fn new_network<A: Into<String>>(host: A, port: u16) -> PrinterProfileBuilder {
+        PrinterProfileBuilder {
+            printer_connection_data: PrinterConnectionData::Network {
+                host: host.into(),
+                port,
+                timeout: Duration::from_secs(2),
+            },
+            columns_per_font: HashMap::new(),
+            width: 384,
+        }
+    }
+
+    /// Sets the amount of columns a given font fits in a line
+    pub fn with_font_width(mut self, font: Font, width: u8) -> Self {
+        self.columns_per_font.insert(font, width);
+        self
+    }
+
+    /// Sets the width of the printer head in dots
+    pub fn with_width(mut self, width: u16) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Overrides the bulk output endpoint (usb printers only)
+    pub fn with_endpoint(mut self, endpoint: u8) -> Self {
+        if let PrinterConnectionData::Usb { endpoint: e, .. } = &mut self.printer_connection_data {
+            *e = Some(endpoint);
+        }
+        self
+    }
+
+    /// Overrides the write timeout
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        match &mut self.printer_connection_data {
+            PrinterConnectionData::Usb { timeout: t, .. } => *t = timeout,
+            PrinterConnectionData::Network { timeout: t, .. } => *t = timeout,
+        }
+        self
+    }
+
+    /// Builds the final [PrinterProfile](crate::PrinterProfile)
+    pub fn build(self) -> PrinterProfile {
+        PrinterProfile {
+            printer_connection_data: self.printer_connection_data,
+            columns_per_font: self.columns_per_font,
+            width: self.width,
+        }
+    }
+}